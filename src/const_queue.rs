@@ -0,0 +1,517 @@
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash};
+
+use crate::DefaultHashBuilder;
+
+struct ConstRingBuffer<T, const CAP: usize> {
+    buf: [Option<T>; CAP],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const CAP: usize> ConstRingBuffer<T, CAP> {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            buf: core::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn index_of(&self, i: usize) -> usize {
+        (self.head + i) % CAP
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> &T {
+        debug_assert!(i < self.len, "ConstRingBuffer index out of bounds");
+        self.buf[self.index_of(i)].as_ref().unwrap()
+    }
+
+    #[inline]
+    fn push_back(&mut self, value: T) {
+        debug_assert!(self.len < CAP, "ConstRingBuffer is at capacity");
+        let i = self.index_of(self.len);
+        self.buf[i] = Some(value);
+        self.len += 1;
+    }
+
+    #[inline]
+    fn pop_front(&mut self) {
+        debug_assert!(!self.is_empty(), "ConstRingBuffer is empty");
+        self.buf[self.head] = None;
+        self.head = (self.head + 1) % CAP;
+        self.len -= 1;
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            let i = self.index_of(self.len - 1);
+            self.buf[i] = None;
+            self.len -= 1;
+        }
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.truncate(0);
+        self.head = 0;
+    }
+}
+
+/// A `no_std`, heap-free variant of [`MinimizerQueue`](crate::MinimizerQueue) whose
+/// `width` is fixed at compile time via the const generic `W`, so it never allocates.
+///
+/// # Examples
+///
+/// ```
+/// use minimizer_queue::ConstMinimizerQueue;
+///
+/// let mut queue = ConstMinimizerQueue::<_, 3>::new(); // width 3
+/// queue.insert(1);
+/// queue.insert(2);
+/// queue.insert(3);
+/// queue.get_min(); // element with the smallest hash among 1, 2 and 3
+/// ```
+pub struct ConstMinimizerQueue<T: Hash + Copy, const W: usize, S: BuildHasher = DefaultHashBuilder>
+{
+    deq: ConstRingBuffer<(T, u64, usize), W>,
+    hash_builder: S,
+    pos: usize,
+}
+
+impl<T: Hash + Copy, const W: usize> ConstMinimizerQueue<T, W> {
+    /// Creates an empty `ConstMinimizerQueue`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_seed(W as u64)
+    }
+
+    /// Creates an empty `ConstMinimizerQueue` with the given seed.
+    /// Changing the seed will change the ordering of the minimizers.
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_hasher(DefaultHashBuilder::with_seed(seed))
+    }
+}
+
+impl<T: Hash + Copy, const W: usize> Default for ConstMinimizerQueue<T, W> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Copy, const W: usize, S: BuildHasher> ConstMinimizerQueue<T, W, S> {
+    /// Creates an empty `ConstMinimizerQueue` with the given hasher.
+    /// The hasher will define the ordering of the minimizers, based on their hashes.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            deq: ConstRingBuffer::new(),
+            hash_builder,
+            pos: 0,
+        }
+    }
+
+    /// Returns the width of the `ConstMinimizerQueue`.
+    #[inline]
+    pub fn width(&self) -> usize {
+        W
+    }
+
+    /// Returns `true` if the `ConstMinimizerQueue` is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deq.is_empty()
+    }
+
+    /// Returns `true` if there are multiple minimizers in the queue.
+    #[inline]
+    pub fn multiple_mins(&self) -> bool {
+        self.deq.len() >= 2 && self.deq.get(0).1 == self.deq.get(1).1
+    }
+
+    /// Returns the leftmost minimizer in the queue.
+    #[inline]
+    pub fn get_min(&self) -> T {
+        debug_assert!(!self.deq.is_empty(), "ConstMinimizerQueue is empty");
+        self.deq.get(0).0
+    }
+
+    /// Returns the leftmost minimizer and its relative position in the queue.
+    #[inline]
+    pub fn get_min_pos(&self) -> (T, usize) {
+        debug_assert!(!self.deq.is_empty(), "ConstMinimizerQueue is empty");
+        let (x, _, pos) = *self.deq.get(0);
+        let rel_pos = (W - self.pos + pos) % W;
+        (x, rel_pos)
+    }
+
+    /// Returns the innermost minimizer and its relative position in the queue, with a second choice in case of tie.
+    #[inline]
+    pub fn get_inner_min_pos(&self) -> (T, usize, Option<(T, usize)>) {
+        debug_assert!(!self.deq.is_empty(), "ConstMinimizerQueue is empty");
+        let start = W - self.pos;
+        let (mut x, hash, x_pos) = *self.deq.get(0);
+        let mut x_pos = (start + x_pos) % W;
+        let mut i = 1;
+        while i < self.deq.len() && self.deq.get(i).1 == hash {
+            let (y, _, y_pos) = *self.deq.get(i);
+            let y_pos = (start + y_pos) % W;
+            match x_pos.cmp(&(W - 1 - y_pos)) {
+                Ordering::Less => {
+                    x = y;
+                    x_pos = y_pos;
+                }
+                Ordering::Equal => return (x, x_pos, Some((y, y_pos))),
+                Ordering::Greater => return (x, x_pos, None),
+            }
+            i += 1;
+        }
+        (x, x_pos, None)
+    }
+
+    /// Inserts `x` in the queue and updates the current minimizer.
+    #[inline]
+    pub fn insert(&mut self, x: T) {
+        self.insert_with_hash(x, self.hash_builder.hash_one(x))
+    }
+
+    /// Inserts `x` in the queue with the given hash and updates the current minimizer.
+    pub fn insert_with_hash(&mut self, x: T, hash: u64) {
+        if !self.deq.is_empty() && self.deq.get(0).2 == self.pos {
+            self.deq.pop_front();
+        }
+        let mut i = self.deq.len();
+        while i > 0 && hash < self.deq.get(i - 1).1 {
+            i -= 1;
+        }
+        self.deq.truncate(i);
+        self.deq.push_back((x, hash, self.pos));
+        self.pos = (self.pos + 1) % W;
+    }
+
+    /// Clears the queue, removing all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.deq.clear()
+    }
+}
+
+#[cfg(test)]
+mod const_minimizer_queue_tests {
+    extern crate std;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use nohash_hasher::BuildNoHashHasher;
+
+    #[test]
+    fn test_get_min() {
+        let mut queue =
+            ConstMinimizerQueue::<usize, 3, _>::with_hasher(BuildNoHashHasher::<usize>::default());
+
+        let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+        let mut mins = Vec::with_capacity(vals.len() - queue.width() + 1);
+
+        for &val in vals.iter().take(queue.width() - 1) {
+            queue.insert(val);
+        }
+        for &val in vals.iter().skip(queue.width() - 1) {
+            queue.insert(val);
+            mins.push(queue.get_min());
+        }
+
+        assert_eq!(mins, vec![1, 0, 0, 0, 7, 8, 3, 3, 3, 4]);
+    }
+
+    #[test]
+    fn test_get_min_pos() {
+        let mut queue =
+            ConstMinimizerQueue::<usize, 3, _>::with_hasher(BuildNoHashHasher::<usize>::default());
+
+        let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+        let mut mins_pos = Vec::with_capacity(vals.len() - queue.width() + 1);
+
+        for &val in vals.iter().take(queue.width() - 1) {
+            queue.insert(val);
+        }
+        for &val in vals.iter().skip(queue.width() - 1) {
+            queue.insert(val);
+            mins_pos.push(queue.get_min_pos());
+        }
+
+        assert_eq!(
+            mins_pos,
+            vec![
+                (1, 0),
+                (0, 2),
+                (0, 1),
+                (0, 0),
+                (7, 0),
+                (8, 0),
+                (3, 2),
+                (3, 1),
+                (3, 0),
+                (4, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_inner_min_pos() {
+        let mut queue =
+            ConstMinimizerQueue::<usize, 3, _>::with_hasher(BuildNoHashHasher::<usize>::default());
+
+        let vals = [1usize, 2, 3, 2, 2, 3, 1];
+        let mut inner_mins_pos = Vec::with_capacity(vals.len() - queue.width() + 1);
+
+        for &val in vals.iter().take(queue.width() - 1) {
+            queue.insert(val);
+        }
+        for &val in vals.iter().skip(queue.width() - 1) {
+            queue.insert(val);
+            inner_mins_pos.push(queue.get_inner_min_pos());
+        }
+
+        assert_eq!(
+            inner_mins_pos,
+            vec![
+                (1, 0, None),
+                (2, 0, Some((2, 2))),
+                (2, 1, None),
+                (2, 1, None),
+                (1, 2, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiple_mins_and_clear() {
+        let mut queue =
+            ConstMinimizerQueue::<usize, 3, _>::with_hasher(BuildNoHashHasher::<usize>::default());
+
+        queue.insert(2);
+        queue.insert(2);
+        assert!(queue.multiple_mins());
+
+        queue.insert(1);
+        assert!(!queue.multiple_mins());
+
+        assert!(!queue.is_empty());
+        queue.clear();
+        assert!(queue.is_empty());
+    }
+}
+
+/// A `no_std`, heap-free variant of [`ImplicitMinimizerQueue`](crate::ImplicitMinimizerQueue)
+/// whose `width` is fixed at compile time via the const generic `W`, so it never allocates.
+///
+/// # Examples
+///
+/// ```
+/// use minimizer_queue::ConstImplicitMinimizerQueue;
+///
+/// let mut queue = ConstImplicitMinimizerQueue::<3>::new(); // width 3
+/// queue.insert(&1);
+/// queue.insert(&2);
+/// queue.insert(&3);
+/// queue.get_min_pos(); // position of the element with the smallest hash among 1, 2 and 3
+/// ```
+pub struct ConstImplicitMinimizerQueue<const W: usize, S: BuildHasher = DefaultHashBuilder> {
+    deq: ConstRingBuffer<(u64, usize), W>,
+    hash_builder: S,
+    pos: usize,
+}
+
+impl<const W: usize> ConstImplicitMinimizerQueue<W> {
+    /// Creates an empty `ConstImplicitMinimizerQueue`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_seed(W as u64)
+    }
+
+    /// Creates an empty `ConstImplicitMinimizerQueue` with the given seed.
+    /// Changing the seed will change the ordering of the minimizers.
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_hasher(DefaultHashBuilder::with_seed(seed))
+    }
+}
+
+impl<const W: usize> Default for ConstImplicitMinimizerQueue<W> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, S: BuildHasher> ConstImplicitMinimizerQueue<W, S> {
+    /// Creates an empty `ConstImplicitMinimizerQueue` with the given hasher.
+    /// The hasher will define the ordering of the minimizers, based on their hashes.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            deq: ConstRingBuffer::new(),
+            hash_builder,
+            pos: 0,
+        }
+    }
+
+    /// Returns the width of the `ConstImplicitMinimizerQueue`.
+    #[inline]
+    pub fn width(&self) -> usize {
+        W
+    }
+
+    /// Returns `true` if the `ConstImplicitMinimizerQueue` is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.deq.is_empty()
+    }
+
+    /// Returns `true` if there are multiple minimizers in the queue.
+    #[inline]
+    pub fn multiple_mins(&self) -> bool {
+        self.deq.len() >= 2 && self.deq.get(0).0 == self.deq.get(1).0
+    }
+
+    /// Returns the relative position of the leftmost minimizer.
+    #[inline]
+    pub fn get_min_pos(&self) -> usize {
+        debug_assert!(!self.deq.is_empty(), "ConstImplicitMinimizerQueue is empty");
+        let (_, pos) = *self.deq.get(0);
+        (W - self.pos + pos) % W
+    }
+
+    /// Returns the relative position of the innermost minimizer, with a second choice in case of tie.
+    #[inline]
+    pub fn get_inner_min_pos(&self) -> (usize, Option<usize>) {
+        debug_assert!(!self.deq.is_empty(), "ConstImplicitMinimizerQueue is empty");
+        let start = W - self.pos;
+        let (hash, x_pos) = *self.deq.get(0);
+        let mut x_pos = (start + x_pos) % W;
+        let mut i = 1;
+        while i < self.deq.len() && self.deq.get(i).0 == hash {
+            let (_, y_pos) = *self.deq.get(i);
+            let y_pos = (start + y_pos) % W;
+            match x_pos.cmp(&(W - 1 - y_pos)) {
+                Ordering::Less => {
+                    x_pos = y_pos;
+                }
+                Ordering::Equal => return (x_pos, Some(y_pos)),
+                Ordering::Greater => return (x_pos, None),
+            }
+            i += 1;
+        }
+        (x_pos, None)
+    }
+
+    /// Inserts `x` in the queue and updates the current minimizer.
+    #[inline]
+    pub fn insert<T: Hash>(&mut self, x: &T) {
+        self.insert_hash(self.hash_builder.hash_one(x))
+    }
+
+    /// Inserts `x` in the queue with the given hash and updates the current minimizer.
+    pub fn insert_hash(&mut self, hash: u64) {
+        if !self.deq.is_empty() && self.deq.get(0).1 == self.pos {
+            self.deq.pop_front();
+        }
+        let mut i = self.deq.len();
+        while i > 0 && hash < self.deq.get(i - 1).0 {
+            i -= 1;
+        }
+        self.deq.truncate(i);
+        self.deq.push_back((hash, self.pos));
+        self.pos = (self.pos + 1) % W;
+    }
+
+    /// Clears the queue, removing all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.deq.clear()
+    }
+}
+
+#[cfg(test)]
+mod const_implicit_minimizer_queue_tests {
+    extern crate std;
+    use std::{vec, vec::Vec};
+
+    use super::*;
+    use nohash_hasher::BuildNoHashHasher;
+
+    #[test]
+    fn test_get_min_pos() {
+        let mut queue = ConstImplicitMinimizerQueue::<3, _>::with_hasher(
+            BuildNoHashHasher::<usize>::default(),
+        );
+
+        let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+        let mut mins_pos = Vec::with_capacity(vals.len() - queue.width() + 1);
+
+        for val in vals.iter().take(queue.width() - 1) {
+            queue.insert(val);
+        }
+        for val in vals.iter().skip(queue.width() - 1) {
+            queue.insert(val);
+            mins_pos.push(queue.get_min_pos());
+        }
+
+        assert_eq!(mins_pos, vec![0, 2, 1, 0, 0, 0, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_get_inner_min_pos() {
+        let mut queue = ConstImplicitMinimizerQueue::<3, _>::with_hasher(
+            BuildNoHashHasher::<usize>::default(),
+        );
+
+        let vals = [1usize, 2, 3, 2, 2, 3, 1];
+        let mut inner_mins_pos = Vec::with_capacity(vals.len() - queue.width() + 1);
+
+        for val in vals.iter().take(queue.width() - 1) {
+            queue.insert(val);
+        }
+        for val in vals.iter().skip(queue.width() - 1) {
+            queue.insert(val);
+            inner_mins_pos.push(queue.get_inner_min_pos());
+        }
+
+        assert_eq!(
+            inner_mins_pos,
+            vec![(0, None), (0, Some(2)), (1, None), (1, None), (2, None)]
+        );
+    }
+
+    #[test]
+    fn test_multiple_mins_and_clear() {
+        let mut queue = ConstImplicitMinimizerQueue::<3, _>::with_hasher(
+            BuildNoHashHasher::<usize>::default(),
+        );
+
+        queue.insert(&2);
+        queue.insert(&2);
+        assert!(queue.multiple_mins());
+
+        queue.insert(&1);
+        assert!(!queue.multiple_mins());
+
+        assert!(!queue.is_empty());
+        queue.clear();
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,394 @@
+use crate::{ImplicitMinimizerQueue, MinimizerQueue};
+use core::hash::Hash;
+
+/// Extension trait that streams minimizers lazily over any iterator.
+///
+/// This removes the usual boilerplate of priming a [`MinimizerQueue`] by hand
+/// (`take(width - 1)` then `skip(width - 1)`, pushing into a `Vec`) and lets
+/// minimizer extraction compose with the rest of an iterator pipeline.
+///
+/// # Examples
+///
+/// ```
+/// use minimizer_queue::MinimizerExt;
+///
+/// let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+/// let mins: Vec<_> = vals.into_iter().minimizers(3).collect();
+/// ```
+pub trait MinimizerExt: Iterator + Sized {
+    /// Streams the minimizer of each window of the given `width`.
+    fn minimizers(self, width: u16) -> Minimizers<Self>
+    where
+        Self::Item: Hash + Copy,
+    {
+        Minimizers::new(self, width)
+    }
+
+    /// Streams the absolute position of the minimizer of each window of the given `width`.
+    fn minimizer_positions(self, width: u16) -> MinimizerPositions<Self>
+    where
+        Self::Item: Hash,
+    {
+        MinimizerPositions::new(self, width)
+    }
+
+    /// Streams the minimizer of each window of the given `width`, paired with its absolute position.
+    fn minimizers_with_pos(self, width: u16) -> MinimizersWithPos<Self>
+    where
+        Self::Item: Hash + Copy,
+    {
+        MinimizersWithPos::new(self, width)
+    }
+
+    /// Groups consecutive windows of the given `width` that share the same minimizer
+    /// occurrence into super-k-mers, yielding `(minimizer, start_window, end_window)`.
+    fn super_kmers(self, width: u16) -> SuperKmers<Self>
+    where
+        Self::Item: Hash + Copy,
+    {
+        SuperKmers::new(self, width)
+    }
+
+    /// Like [`super_kmers`](MinimizerExt::super_kmers), but only tracking the minimizer's
+    /// absolute position rather than the value itself, yielding `(pos, start_window, end_window)`.
+    fn super_kmer_positions(self, width: u16) -> ImplicitSuperKmers<Self>
+    where
+        Self::Item: Hash,
+    {
+        ImplicitSuperKmers::new(self, width)
+    }
+}
+
+impl<I: Iterator> MinimizerExt for I {}
+
+/// Feeds up to `width - 1` items from `iter` into `insert` to prime a queue, stopping
+/// early if `iter` runs out first. Returns the number of items actually fed.
+fn prime<I: Iterator>(iter: &mut I, width: u16, mut insert: impl FnMut(I::Item)) -> usize {
+    let mut primed = 0;
+    for _ in 0..width.saturating_sub(1) {
+        match iter.next() {
+            Some(x) => {
+                insert(x);
+                primed += 1;
+            }
+            None => break,
+        }
+    }
+    primed
+}
+
+/// Iterator adaptor returned by [`MinimizerExt::minimizers`].
+pub struct Minimizers<I: Iterator>
+where
+    I::Item: Hash + Copy,
+{
+    iter: I,
+    queue: MinimizerQueue<I::Item>,
+}
+
+impl<I: Iterator> Minimizers<I>
+where
+    I::Item: Hash + Copy,
+{
+    fn new(mut iter: I, width: u16) -> Self {
+        let mut queue = MinimizerQueue::new(width);
+        prime(&mut iter, width, |x| queue.insert(x));
+        Self { iter, queue }
+    }
+}
+
+impl<I: Iterator> Iterator for Minimizers<I>
+where
+    I::Item: Hash + Copy,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        self.queue.insert(x);
+        Some(self.queue.get_min())
+    }
+}
+
+/// Iterator adaptor returned by [`MinimizerExt::minimizer_positions`].
+pub struct MinimizerPositions<I: Iterator> {
+    iter: I,
+    queue: ImplicitMinimizerQueue,
+    window: usize,
+}
+
+impl<I: Iterator> MinimizerPositions<I>
+where
+    I::Item: Hash,
+{
+    fn new(mut iter: I, width: u16) -> Self {
+        let mut queue = ImplicitMinimizerQueue::new(width);
+        prime(&mut iter, width, |x| queue.insert(&x));
+        Self {
+            iter,
+            queue,
+            window: 0,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for MinimizerPositions<I>
+where
+    I::Item: Hash,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        self.queue.insert(&x);
+        let pos = self.window + self.queue.get_min_pos();
+        self.window += 1;
+        Some(pos)
+    }
+}
+
+/// Iterator adaptor returned by [`MinimizerExt::minimizers_with_pos`].
+pub struct MinimizersWithPos<I: Iterator>
+where
+    I::Item: Hash + Copy,
+{
+    iter: I,
+    queue: MinimizerQueue<I::Item>,
+    window: usize,
+}
+
+impl<I: Iterator> MinimizersWithPos<I>
+where
+    I::Item: Hash + Copy,
+{
+    fn new(mut iter: I, width: u16) -> Self {
+        let mut queue = MinimizerQueue::new(width);
+        prime(&mut iter, width, |x| queue.insert(x));
+        Self {
+            iter,
+            queue,
+            window: 0,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for MinimizersWithPos<I>
+where
+    I::Item: Hash + Copy,
+{
+    type Item = (I::Item, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        self.queue.insert(x);
+        let (min, rel_pos) = self.queue.get_min_pos();
+        let pos = self.window + rel_pos;
+        self.window += 1;
+        Some((min, pos))
+    }
+}
+
+/// Iterator adaptor returned by [`MinimizerExt::super_kmers`].
+///
+/// Coalesces consecutive windows whose leftmost minimizer is the same occurrence
+/// (i.e. the same absolute position) into a single `(minimizer, start_window, end_window)`
+/// run, in the spirit of itertools' `coalesce`.
+pub struct SuperKmers<I: Iterator>
+where
+    I::Item: Hash + Copy,
+{
+    iter: I,
+    queue: MinimizerQueue<I::Item>,
+    next_window: usize,
+    run: Option<(I::Item, usize, usize, usize)>,
+}
+
+impl<I: Iterator> SuperKmers<I>
+where
+    I::Item: Hash + Copy,
+{
+    fn new(mut iter: I, width: u16) -> Self {
+        let mut queue = MinimizerQueue::new(width);
+        prime(&mut iter, width, |x| queue.insert(x));
+        Self {
+            iter,
+            queue,
+            next_window: 0,
+            run: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for SuperKmers<I>
+where
+    I::Item: Hash + Copy,
+{
+    type Item = (I::Item, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(x) = self.iter.next() else {
+                return self.run.take().map(|(val, _, start, end)| (val, start, end));
+            };
+            self.queue.insert(x);
+            let (min, rel_pos) = self.queue.get_min_pos();
+            let window = self.next_window;
+            let abs_pos = window + rel_pos;
+            self.next_window += 1;
+
+            match &mut self.run {
+                Some((_, pos, _, end)) if *pos == abs_pos => {
+                    *end = window;
+                }
+                Some(_) => {
+                    let finished = self.run.replace((min, abs_pos, window, window)).unwrap();
+                    return Some((finished.0, finished.2, finished.3));
+                }
+                None => {
+                    self.run = Some((min, abs_pos, window, window));
+                }
+            }
+        }
+    }
+}
+
+/// Iterator adaptor returned by [`MinimizerExt::super_kmer_positions`].
+pub struct ImplicitSuperKmers<I: Iterator> {
+    iter: I,
+    queue: ImplicitMinimizerQueue,
+    next_window: usize,
+    run: Option<(usize, usize, usize)>,
+}
+
+impl<I: Iterator> ImplicitSuperKmers<I>
+where
+    I::Item: Hash,
+{
+    fn new(mut iter: I, width: u16) -> Self {
+        let mut queue = ImplicitMinimizerQueue::new(width);
+        prime(&mut iter, width, |x| queue.insert(&x));
+        Self {
+            iter,
+            queue,
+            next_window: 0,
+            run: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for ImplicitSuperKmers<I>
+where
+    I::Item: Hash,
+{
+    type Item = (usize, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(x) = self.iter.next() else {
+                return self.run.take();
+            };
+            self.queue.insert(&x);
+            let rel_pos = self.queue.get_min_pos();
+            let window = self.next_window;
+            let abs_pos = window + rel_pos;
+            self.next_window += 1;
+
+            match &mut self.run {
+                Some((pos, _, end)) if *pos == abs_pos => {
+                    *end = window;
+                }
+                Some(_) => {
+                    let finished = self.run.replace((abs_pos, window, window)).unwrap();
+                    return Some(finished);
+                }
+                None => {
+                    self.run = Some((abs_pos, window, window));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MinimizerQueue;
+
+    fn naive_mins(vals: &[usize], width: u16) -> Vec<usize> {
+        let mut queue = MinimizerQueue::new(width);
+        let mut mins = Vec::new();
+        for &val in vals.iter().take(queue.width() - 1) {
+            queue.insert(val);
+        }
+        for &val in vals.iter().skip(queue.width() - 1) {
+            queue.insert(val);
+            mins.push(queue.get_min());
+        }
+        mins
+    }
+
+    #[test]
+    fn test_minimizers_matches_manual_loop() {
+        let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+        let expected = naive_mins(&vals, 3);
+        let mins: Vec<_> = vals.into_iter().minimizers(3).collect();
+        assert_eq!(mins, expected);
+    }
+
+    #[test]
+    fn test_minimizers_with_pos_matches_positions_and_values() {
+        let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+        let mins: Vec<_> = vals.into_iter().minimizers(3).collect();
+        let positions: Vec<_> = vals.into_iter().minimizer_positions(3).collect();
+        let with_pos: Vec<_> = vals.into_iter().minimizers_with_pos(3).collect();
+        let rebuilt: Vec<_> = mins.into_iter().zip(positions).collect();
+        assert_eq!(with_pos, rebuilt);
+    }
+
+    #[test]
+    fn test_minimizers_short_input_yields_nothing() {
+        let vals = [1usize, 2];
+        let mins: Vec<_> = vals.into_iter().minimizers(3).collect();
+        assert!(mins.is_empty());
+    }
+
+    #[test]
+    fn test_super_kmers_covers_every_window_without_overlap() {
+        let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+        let width = 3;
+        let num_windows = vals.len() - width as usize + 1;
+        let runs: Vec<_> = vals.into_iter().super_kmers(width).collect();
+
+        assert_eq!(runs[0].1, 0);
+        assert_eq!(runs.last().unwrap().2, num_windows - 1);
+        for window in runs.windows(2) {
+            assert_eq!(window[0].2 + 1, window[1].1);
+        }
+
+        let mins: Vec<_> = vals.into_iter().minimizers(width).collect();
+        let mut rebuilt = Vec::with_capacity(num_windows);
+        for (min, start, end) in &runs {
+            for _ in *start..=*end {
+                rebuilt.push(*min);
+            }
+        }
+        assert_eq!(rebuilt, mins);
+    }
+
+    #[test]
+    fn test_super_kmer_positions_matches_super_kmers() {
+        let vals = [1usize, 2, 3, 0, 7, 8, 9, 100, 3, 4, 7, 8];
+        let width = 3;
+        let runs: Vec<_> = vals.into_iter().super_kmers(width).collect();
+        let pos_runs: Vec<_> = vals
+            .into_iter()
+            .super_kmer_positions(width)
+            .map(|(_, start, end)| (start, end))
+            .collect();
+        let runs_without_values: Vec<_> = runs.into_iter().map(|(_, start, end)| (start, end)).collect();
+        assert_eq!(pos_runs, runs_without_values);
+    }
+}
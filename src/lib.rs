@@ -1,11 +1,65 @@
+// `std` is enabled by default (see the `std` feature in Cargo.toml); disabling it
+// drops the heap-backed `MinimizerQueue`/`ImplicitMinimizerQueue` (and the
+// iterator adaptors built on them), leaving only the stack-only
+// `ConstMinimizerQueue`/`ConstImplicitMinimizerQueue` below.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 use core::cmp::Ordering;
+#[cfg(feature = "std")]
 use core::hash::{BuildHasher, Hash};
-use std::collections::VecDeque;
+#[cfg(feature = "std")]
 use strength_reduce::StrengthReducedU16;
 
+#[cfg(feature = "std")]
+mod ring;
+#[cfg(feature = "std")]
+use ring::RingBuffer;
+
+mod const_queue;
+pub use const_queue::{ConstImplicitMinimizerQueue, ConstMinimizerQueue};
+
+#[cfg(feature = "std")]
+mod iter;
+#[cfg(feature = "std")]
+pub use iter::{
+    ImplicitSuperKmers, MinimizerExt, MinimizerPositions, Minimizers, MinimizersWithPos,
+    SuperKmers,
+};
+
 /// Default hasher for [`MinimizerQueue`] and [`ImplicitMinimizerQueue`].
 pub type DefaultHashBuilder = wyhash2::WyHash;
 
+/// Ranks values to decide which one a [`MinimizerQueue`] selects as the minimizer.
+///
+/// Implemented for every [`BuildHasher`] (the default: rank by hash) and for [`FnOrder`]
+/// (rank by a user-supplied priority, e.g. a frequency table).
+#[cfg(feature = "std")]
+pub trait Order<T> {
+    /// Returns the priority of `x`: the queue selects the entry with the smallest priority.
+    fn priority(&self, x: &T) -> u64;
+}
+
+#[cfg(feature = "std")]
+impl<T: Hash, S: BuildHasher> Order<T> for S {
+    #[inline]
+    fn priority(&self, x: &T) -> u64 {
+        self.hash_one(x)
+    }
+}
+
+/// An [`Order`] driven by a closure, e.g. a lookup into a precomputed priority table.
+#[cfg(feature = "std")]
+pub struct FnOrder<F>(pub F);
+
+#[cfg(feature = "std")]
+impl<T, F: Fn(&T) -> u64> Order<T> for FnOrder<F> {
+    #[inline]
+    fn priority(&self, x: &T) -> u64 {
+        (self.0)(x)
+    }
+}
+
 /// A monotone queue that can compute consecutive minimizers in constant time.
 ///
 /// # Examples
@@ -22,13 +76,15 @@ pub type DefaultHashBuilder = wyhash2::WyHash;
 /// queue.insert(4);
 /// queue.get_min(); // element with the smallest hash among 2, 3 and 4
 /// ```
-pub struct MinimizerQueue<T: Hash + Copy, S: BuildHasher = DefaultHashBuilder> {
-    deq: VecDeque<(T, u64, u16)>,
+#[cfg(feature = "std")]
+pub struct MinimizerQueue<T: Hash + Copy, S: Order<T> = DefaultHashBuilder> {
+    deq: RingBuffer<(T, u64, u16)>,
     width: StrengthReducedU16,
-    hash_builder: S,
+    order: S,
     pos: u16,
 }
 
+#[cfg(feature = "std")]
 impl<T: Hash + Copy> MinimizerQueue<T> {
     /// Creates an empty `MinimizerQueue` with the given width.
     #[inline]
@@ -42,20 +98,52 @@ impl<T: Hash + Copy> MinimizerQueue<T> {
     pub fn with_seed(width: u16, seed: u64) -> Self {
         Self::with_hasher(width, DefaultHashBuilder::with_seed(seed))
     }
+
+    /// Creates an empty `MinimizerQueue` with the given width and a custom [`Order`],
+    /// e.g. a closure looking up a priority in a precomputed table rather than hashing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minimizer_queue::{FnOrder, MinimizerQueue};
+    /// use std::collections::HashMap;
+    ///
+    /// // rare values should win: lower count means higher priority (lower key)
+    /// let counts: HashMap<usize, u64> = HashMap::from([(1, 3), (2, 1), (3, 2)]);
+    /// let mut queue = MinimizerQueue::with_order(3, FnOrder(move |x: &usize| {
+    ///     *counts.get(x).unwrap_or(&u64::MAX)
+    /// }));
+    /// queue.insert(1);
+    /// queue.insert(2);
+    /// queue.insert(3);
+    /// assert_eq!(queue.get_min(), 2);
+    /// ```
+    pub fn with_order<S: Order<T>>(width: u16, order: S) -> MinimizerQueue<T, S> {
+        MinimizerQueue {
+            deq: RingBuffer::with_capacity(width as usize),
+            width: StrengthReducedU16::new(width),
+            order,
+            pos: 0,
+        }
+    }
 }
 
+#[cfg(feature = "std")]
 impl<T: Hash + Copy, S: BuildHasher> MinimizerQueue<T, S> {
     /// Creates an empty `MinimizerQueue` with the given width and hasher.
     /// The hasher will define the ordering of the minimizers, based on their hashes.
     pub fn with_hasher(width: u16, hash_builder: S) -> Self {
         Self {
-            deq: VecDeque::with_capacity(width as usize),
+            deq: RingBuffer::with_capacity(width as usize),
             width: StrengthReducedU16::new(width),
-            hash_builder,
+            order: hash_builder,
             pos: 0,
         }
     }
+}
 
+#[cfg(feature = "std")]
+impl<T: Hash + Copy, S: Order<T>> MinimizerQueue<T, S> {
     /// Returns the width of the `MinimizerQueue`.
     #[inline]
     pub fn width(&self) -> usize {
@@ -118,10 +206,11 @@ impl<T: Hash + Copy, S: BuildHasher> MinimizerQueue<T, S> {
     /// Inserts `x` in the queue and updates the current minimizer.
     #[inline]
     pub fn insert(&mut self, x: T) {
-        self.insert_with_hash(x, self.hash_builder.hash_one(x))
+        self.insert_with_hash(x, self.order.priority(&x))
     }
 
-    /// Inserts `x` in the queue with the given hash and updates the current minimizer.
+    /// Inserts `x` in the queue with the given hash (or priority, for a custom [`Order`])
+    /// and updates the current minimizer.
     pub fn insert_with_hash(&mut self, x: T, hash: u64) {
         if !self.deq.is_empty() && self.deq[0].2 == self.pos {
             self.deq.pop_front();
@@ -158,13 +247,15 @@ impl<T: Hash + Copy, S: BuildHasher> MinimizerQueue<T, S> {
 /// queue.insert(&4);
 /// queue.get_min_pos(); // position of the element with the smallest hash among 2, 3 and 4
 /// ```
+#[cfg(feature = "std")]
 pub struct ImplicitMinimizerQueue<S: BuildHasher = DefaultHashBuilder> {
-    deq: VecDeque<(u64, u16)>,
+    deq: RingBuffer<(u64, u16)>,
     width: StrengthReducedU16,
     hash_builder: S,
     pos: u16,
 }
 
+#[cfg(feature = "std")]
 impl ImplicitMinimizerQueue {
     /// Creates an empty `ImplicitMinimizerQueue` with the given width.
     #[inline]
@@ -180,12 +271,13 @@ impl ImplicitMinimizerQueue {
     }
 }
 
+#[cfg(feature = "std")]
 impl<S: BuildHasher> ImplicitMinimizerQueue<S> {
     /// Creates an empty `ImplicitMinimizerQueue` with the given width and hasher.
     /// The hasher will define the ordering of the minimizers, based on their hashes.
     pub fn with_hasher(width: u16, hash_builder: S) -> Self {
         Self {
-            deq: VecDeque::with_capacity(width as usize),
+            deq: RingBuffer::with_capacity(width as usize),
             width: StrengthReducedU16::new(width),
             hash_builder,
             pos: 0,
@@ -269,7 +361,7 @@ impl<S: BuildHasher> ImplicitMinimizerQueue<S> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use nohash_hasher::BuildNoHashHasher;
@@ -391,4 +483,23 @@ mod tests {
             vec![(0, None), (0, Some(2)), (1, None), (1, None), (2, None),]
         );
     }
+
+    #[test]
+    fn test_with_order_picks_lowest_priority() {
+        // rarer values should win: lower count means higher priority (lower key)
+        let counts: std::collections::HashMap<usize, u64> =
+            std::collections::HashMap::from([(1, 3), (2, 1), (3, 2)]);
+        let mut queue = MinimizerQueue::with_order(
+            3,
+            FnOrder(move |x: &usize| *counts.get(x).unwrap_or(&u64::MAX)),
+        );
+
+        queue.insert(1);
+        queue.insert(2);
+        queue.insert(3);
+        assert_eq!(queue.get_min(), 2);
+
+        queue.insert(1);
+        assert_eq!(queue.get_min(), 2);
+    }
 }
@@ -0,0 +1,84 @@
+use core::ops::Index;
+
+/// A fixed-capacity circular buffer backing [`MinimizerQueue`](crate::MinimizerQueue) and
+/// [`ImplicitMinimizerQueue`](crate::ImplicitMinimizerQueue).
+///
+/// The monotone-queue invariant guarantees the queue never holds more than `width`
+/// entries, so unlike a growable `VecDeque` this buffer allocates exactly once, at
+/// construction, and never reallocates.
+#[cfg(feature = "std")]
+pub(crate) struct RingBuffer<T> {
+    buf: Box<[Option<T>]>,
+    head: usize,
+    len: usize,
+}
+
+#[cfg(feature = "std")]
+impl<T> RingBuffer<T> {
+    #[inline]
+    pub(crate) fn with_capacity(cap: usize) -> Self {
+        Self {
+            buf: (0..cap).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn index_of(&self, i: usize) -> usize {
+        (self.head + i) % self.buf.len()
+    }
+
+    #[inline]
+    pub(crate) fn push_back(&mut self, value: T) {
+        debug_assert!(self.len < self.buf.len(), "RingBuffer is at capacity");
+        let i = self.index_of(self.len);
+        self.buf[i] = Some(value);
+        self.len += 1;
+    }
+
+    #[inline]
+    pub(crate) fn pop_front(&mut self) {
+        debug_assert!(!self.is_empty(), "RingBuffer is empty");
+        self.buf[self.head] = None;
+        self.head = (self.head + 1) % self.buf.len();
+        self.len -= 1;
+    }
+
+    /// Keeps only the first `len` entries, dropping the rest from the back.
+    #[inline]
+    pub(crate) fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            let i = self.index_of(self.len - 1);
+            self.buf[i] = None;
+            self.len -= 1;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.truncate(0);
+        self.head = 0;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Index<usize> for RingBuffer<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, i: usize) -> &T {
+        debug_assert!(i < self.len, "RingBuffer index out of bounds");
+        self.buf[self.index_of(i)].as_ref().unwrap()
+    }
+}